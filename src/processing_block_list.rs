@@ -4,6 +4,7 @@ use crate::{
     common::*,
     error::{ErrorChecker, Result as RsResult},
     processing_block::{marker as processing_block_marker, ProcessingBlock},
+    processing_chain::ProcessingChain,
 };
 
 /// The iterable list of [ProcessingBlock](ProcessingBlock)s.
@@ -46,6 +47,14 @@ impl ProcessingBlockList {
         Ok(self.len()? == 0)
     }
 
+    /// Builds an executable [ProcessingChain] from the blocks in this list,
+    /// wiring each block's output frame queue into the next block's input so
+    /// a frame can be pushed through every recommended processing step with
+    /// a single [ProcessingChain::process](ProcessingChain::process) call.
+    pub fn into_chain(self) -> RsResult<ProcessingChain> {
+        ProcessingChain::from_list(self)
+    }
+
     /// Converts to iterator type.
     pub fn try_into_iter(mut self) -> RsResult<ProcessingBlockListIntoIter> {
         let len = self.len()?;
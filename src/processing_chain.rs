@@ -0,0 +1,73 @@
+//! Defines an executable chain of [ProcessingBlock](crate::processing_block::ProcessingBlock)s.
+
+use crate::{
+    error::Result as RsResult,
+    frame::{marker as frame_marker, Frame},
+    frame_queue::FrameQueue,
+    processing_block::{marker as processing_block_marker, ProcessingBlock},
+    processing_block_list::ProcessingBlockList,
+};
+
+/// Timeout, in milliseconds, used while waiting for a stage to emit its
+/// output frame.
+const STAGE_TIMEOUT_MS: u32 = 5000;
+
+/// A block wired to the queue its output is routed through.
+struct Stage {
+    block: ProcessingBlock<processing_block_marker::Any>,
+    queue: FrameQueue,
+}
+
+/// An executable chain of [ProcessingBlock](crate::processing_block::ProcessingBlock)s, built from a
+/// [ProcessingBlockList](ProcessingBlockList) via
+/// [ProcessingBlockList::into_chain](ProcessingBlockList::into_chain).
+///
+/// Each block's output frame queue feeds directly into the next block, so a
+/// frame can be pushed through every recommended processing step with a
+/// single call:
+///
+/// ```ignore
+/// let mut chain = sensor.recommended_processing_blocks()?.into_chain()?;
+/// let filtered = chain.process(depth_frame)?;
+/// ```
+pub struct ProcessingChain {
+    stages: Vec<Stage>,
+}
+
+impl ProcessingChain {
+    pub(crate) fn from_list(mut list: ProcessingBlockList) -> RsResult<Self> {
+        let len = list.len()?;
+        let mut stages = Vec::with_capacity(len);
+        for index in 0..len {
+            let block = list.get(index)?;
+            let queue = FrameQueue::new(1)?;
+            block.start(&queue)?;
+            stages.push(Stage { block, queue });
+        }
+        Ok(Self { stages })
+    }
+
+    /// Pushes `frame` through every block in the chain, in order, returning
+    /// the final processed frame.
+    ///
+    /// Each stage's output is read back from its queue before being fed into
+    /// the next stage; an error from any stage aborts the chain.
+    pub fn process(&mut self, frame: Frame<frame_marker::Any>) -> RsResult<Frame<frame_marker::Any>> {
+        let mut current = frame;
+        for stage in &mut self.stages {
+            stage.block.process(current)?;
+            current = stage.queue.wait_for_frame(STAGE_TIMEOUT_MS)?;
+        }
+        Ok(current)
+    }
+
+    /// Returns the number of blocks in the chain.
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Checks if the chain has no blocks.
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+}
@@ -3,9 +3,18 @@ use crate::{
     error::{ErrorChecker, Result as RsResult},
     kind::{CameraInfo, Extension, Rs2Option},
     processing_block_list::ProcessingBlockList,
+    sensor_notification::{notification_trampoline, SensorNotification},
     stream_profile_list::StreamProfileList,
 };
-use std::{ffi::CStr, marker::PhantomData, mem::MaybeUninit, ptr::NonNull};
+use std::{
+    cell::Cell,
+    ffi::CStr,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    os::raw::c_void,
+    ptr::{self, NonNull},
+    sync::mpsc::{self, Receiver},
+};
 
 pub mod marker {
     use super::*;
@@ -86,6 +95,16 @@ pub mod marker {
     }
 }
 
+/// Describes the valid range, step size, and default value of an option, as
+/// queried via [Sensor::option_range](Sensor::option_range).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rs2OptionRange {
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    pub default: f32,
+}
+
 #[derive(Debug)]
 pub enum ExtendedSensor {
     Color(Sensor<marker::Color>),
@@ -107,6 +126,7 @@ where
     Kind: marker::SensorKind,
 {
     ptr: NonNull<realsense_sys::rs2_sensor>,
+    notification_user_data: Cell<*mut c_void>,
     _phantom: PhantomData<Kind>,
 }
 
@@ -144,19 +164,132 @@ where
         }
     }
 
-    // pub fn set_option(&mut self, option: Rs2Option, value: f32) -> RsResult<()> {
-    //     unsafe {
-    //         let mut checker = ErrorChecker::new();
-    //         let val = realsense_sys::rs2_set_option(
-    //             self.ptr.as_ptr().cast::<realsense_sys::rs2_options>(),
-    //             option as realsense_sys::rs2_option,
-    //             value,
-    //             checker.inner_mut_ptr(),
-    //         );
-    //         checker.check()?;
-    //     }
-    //     Ok(())
-    // }
+    /// Sets an attribute on sensor.
+    ///
+    /// `value` is clamped to the range reported by
+    /// [Sensor::option_range](Sensor::option_range) before being written, so
+    /// callers cannot trigger a silent firmware rejection by passing an
+    /// out-of-range value. If the queried range itself is degenerate (a
+    /// `NaN` bound, or `min > max`), `value` is passed through unclamped and
+    /// left for the driver to accept or reject, rather than handing it to
+    /// `f32::clamp`, which panics on a malformed range.
+    pub fn set_option(&mut self, option: Rs2Option, value: f32) -> RsResult<()> {
+        let range = self.option_range(option)?;
+        let value = if range.min.is_nan() || range.max.is_nan() || range.min > range.max {
+            value
+        } else {
+            value.clamp(range.min, range.max)
+        };
+        unsafe {
+            let mut checker = ErrorChecker::new();
+            realsense_sys::rs2_set_option(
+                self.ptr.as_ptr().cast::<realsense_sys::rs2_options>(),
+                option as realsense_sys::rs2_option,
+                value,
+                checker.inner_mut_ptr(),
+            );
+            checker.check()?;
+        }
+        Ok(())
+    }
+
+    /// Checks whether `option` is supported on sensor.
+    pub fn supports_option(&self, option: Rs2Option) -> RsResult<bool> {
+        let val = unsafe {
+            let mut checker = ErrorChecker::new();
+            let val = realsense_sys::rs2_supports_option(
+                self.ptr.as_ptr().cast::<realsense_sys::rs2_options>(),
+                option as realsense_sys::rs2_option,
+                checker.inner_mut_ptr(),
+            );
+            checker.check()?;
+            val
+        };
+        Ok(val != 0)
+    }
+
+    /// Checks whether `option` can only be read, and never written, on sensor.
+    pub fn is_option_read_only(&self, option: Rs2Option) -> RsResult<bool> {
+        let val = unsafe {
+            let mut checker = ErrorChecker::new();
+            let val = realsense_sys::rs2_is_option_read_only(
+                self.ptr.as_ptr().cast::<realsense_sys::rs2_options>(),
+                option as realsense_sys::rs2_option,
+                checker.inner_mut_ptr(),
+            );
+            checker.check()?;
+            val
+        };
+        Ok(val != 0)
+    }
+
+    /// Gets the valid range, step size, and default value of `option`.
+    pub fn option_range(&self, option: Rs2Option) -> RsResult<Rs2OptionRange> {
+        let (min, max, step, default) = unsafe {
+            let mut checker = ErrorChecker::new();
+            let mut min: f32 = 0.0;
+            let mut max: f32 = 0.0;
+            let mut step: f32 = 0.0;
+            let mut default: f32 = 0.0;
+            realsense_sys::rs2_get_option_range(
+                self.ptr.as_ptr().cast::<realsense_sys::rs2_options>(),
+                option as realsense_sys::rs2_option,
+                &mut min,
+                &mut max,
+                &mut step,
+                &mut default,
+                checker.inner_mut_ptr(),
+            );
+            checker.check()?;
+            (min, max, step, default)
+        };
+        Ok(Rs2OptionRange {
+            min,
+            max,
+            step,
+            default,
+        })
+    }
+
+    /// Gets the human-readable description of `option`.
+    pub fn option_description(&self, option: Rs2Option) -> RsResult<&CStr> {
+        let ptr = unsafe {
+            let mut checker = ErrorChecker::new();
+            let ptr = realsense_sys::rs2_get_option_description(
+                self.ptr.as_ptr().cast::<realsense_sys::rs2_options>(),
+                option as realsense_sys::rs2_option,
+                checker.inner_mut_ptr(),
+            );
+            checker.check()?;
+            ptr
+        };
+        Ok(unsafe { CStr::from_ptr(ptr) })
+    }
+
+    /// Gets the human-readable description of `value` for `option`, if the
+    /// sensor defines one.
+    pub fn option_value_description(
+        &self,
+        option: Rs2Option,
+        value: f32,
+    ) -> RsResult<Option<&CStr>> {
+        let ptr = unsafe {
+            let mut checker = ErrorChecker::new();
+            let ptr = realsense_sys::rs2_get_option_value_description(
+                self.ptr.as_ptr().cast::<realsense_sys::rs2_options>(),
+                option as realsense_sys::rs2_option,
+                value,
+                checker.inner_mut_ptr(),
+            );
+            checker.check()?;
+            ptr
+        };
+        if ptr.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(unsafe { CStr::from_ptr(ptr) }))
+        }
+    }
 
     /// List stream profiles on sensor.
     pub fn stream_profiles(&self) -> RsResult<StreamProfileList> {
@@ -250,6 +383,57 @@ where
         Ok(string)
     }
 
+    /// Gets an attribute on sensor, tolerating a null result.
+    ///
+    /// Shared by [Sensor::info_string](Sensor::info_string) and
+    /// [Sensor::info_list](Sensor::info_list) so both agree on what "field
+    /// absent" means instead of duplicating the raw FFI call.
+    fn info_raw(&self, kind: CameraInfo) -> RsResult<Option<&CStr>> {
+        let ptr = unsafe {
+            let mut checker = ErrorChecker::new();
+            let ptr = realsense_sys::rs2_get_sensor_info(
+                self.ptr.as_ptr(),
+                kind as realsense_sys::rs2_camera_info,
+                checker.inner_mut_ptr(),
+            );
+            checker.check()?;
+            ptr
+        };
+        if ptr.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(unsafe { CStr::from_ptr(ptr) }))
+        }
+    }
+
+    /// Gets an attribute on sensor as an owned, UTF-8 lossy string.
+    ///
+    /// Returns an empty [String] if the underlying info string is a null
+    /// pointer.
+    pub fn info_string(&self, kind: CameraInfo) -> RsResult<String> {
+        Ok(self
+            .info_raw(kind)?
+            .map(|text| text.to_string_lossy().into_owned())
+            .unwrap_or_default())
+    }
+
+    /// Gets an attribute on sensor and splits it on `delim` into owned
+    /// segments, for multi-valued fields such as USB descriptor lists or
+    /// supported-format enumerations.
+    ///
+    /// Returns an empty [Vec] if the underlying info string is a null
+    /// pointer.
+    pub fn info_list(&self, kind: CameraInfo, delim: char) -> RsResult<Vec<String>> {
+        let Some(text) = self.info_raw(kind)? else {
+            return Ok(Vec::new());
+        };
+        Ok(text
+            .to_string_lossy()
+            .split(delim)
+            .map(str::to_owned)
+            .collect())
+    }
+
     pub fn is_info_supported(&self, kind: CameraInfo) -> RsResult<bool> {
         let val = unsafe {
             let mut checker = ErrorChecker::new();
@@ -264,6 +448,73 @@ where
         Ok(val != 0)
     }
 
+    /// Subscribes to the stream of [SensorNotification]s (hardware errors,
+    /// dropped frames, firmware events, ...) reported by this sensor.
+    ///
+    /// Only one subscription is active at a time; subscribing again replaces
+    /// the previous one. The subscription is cancelled automatically when the
+    /// sensor is dropped.
+    pub fn subscribe_notifications(&self) -> RsResult<Receiver<SensorNotification>> {
+        self.unsubscribe_notifications();
+
+        let (tx, rx) = mpsc::channel();
+        let user_data = Box::into_raw(Box::new(tx)).cast::<c_void>();
+
+        let result = unsafe {
+            let mut checker = ErrorChecker::new();
+            realsense_sys::rs2_set_notifications_callback(
+                self.ptr.as_ptr(),
+                Some(notification_trampoline),
+                user_data,
+                checker.inner_mut_ptr(),
+            );
+            checker.check()
+        };
+
+        if let Err(err) = result {
+            // SAFETY: the callback was never installed, so we still own `user_data`.
+            unsafe { drop(Box::from_raw(user_data.cast::<mpsc::Sender<SensorNotification>>())) };
+            return Err(err);
+        }
+
+        self.notification_user_data.set(user_data);
+        Ok(rx)
+    }
+
+    /// Cancels the current notification subscription, if any, and reclaims
+    /// the user-data box handed to `rs2_set_notifications_callback`.
+    ///
+    /// If clearing the native callback fails, the box is not freed and the
+    /// pointer stays stored on the sensor.
+    fn unsubscribe_notifications(&self) {
+        let user_data = self.notification_user_data.replace(ptr::null_mut());
+        if user_data.is_null() {
+            return;
+        }
+        unsafe {
+            let mut checker = ErrorChecker::new();
+            realsense_sys::rs2_set_notifications_callback(
+                self.ptr.as_ptr(),
+                None,
+                ptr::null_mut(),
+                checker.inner_mut_ptr(),
+            );
+            if checker.check().is_err() {
+                // The native callback may still be registered and could fire
+                // with this `user_data` at any time, so freeing it here would
+                // be a use-after-free. Leak it instead of reclaiming memory
+                // the driver might still reference; put the pointer back so a
+                // later successful unsubscribe (or the next subscribe) can
+                // still try to clear and reclaim it.
+                self.notification_user_data.set(user_data);
+                return;
+            }
+            drop(Box::from_raw(
+                user_data.cast::<mpsc::Sender<SensorNotification>>(),
+            ));
+        }
+    }
+
     pub(crate) unsafe fn take(mut self) -> NonNull<realsense_sys::rs2_sensor> {
         let ptr = std::mem::replace(&mut self.ptr, MaybeUninit::uninit().assume_init());
         std::mem::forget(self);
@@ -273,6 +524,7 @@ where
     pub(crate) unsafe fn from_ptr(ptr: NonNull<realsense_sys::rs2_sensor>) -> Self {
         Self {
             ptr,
+            notification_user_data: Cell::new(ptr::null_mut()),
             _phantom: PhantomData,
         }
     }
@@ -298,9 +550,11 @@ impl Sensor<marker::Any> {
         NewKind: marker::NonAnySensorKind,
     {
         if self.is_extendable_to(NewKind::TYPE)? {
+            let notification_user_data = self.notification_user_data.get();
             let ptr = unsafe { self.take() };
             let sensor = Sensor {
                 ptr,
+                notification_user_data: Cell::new(notification_user_data),
                 _phantom: PhantomData,
             };
             Ok(Ok(sensor))
@@ -375,6 +629,7 @@ where
     Kind: marker::SensorKind,
 {
     fn drop(&mut self) {
+        self.unsubscribe_notifications();
         unsafe {
             realsense_sys::rs2_delete_sensor(self.ptr.as_ptr());
         }
@@ -0,0 +1,159 @@
+//! Defines the notification payload delivered by
+//! [Sensor::subscribe_notifications](crate::sensor::Sensor::subscribe_notifications).
+
+use crate::error::ErrorChecker;
+use std::{ffi::CStr, fmt, os::raw::c_void, sync::mpsc};
+
+/// Severity of a [SensorNotification].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationSeverity {
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl fmt::Display for NotificationSeverity {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+            Self::Fatal => "fatal",
+        };
+        formatter.write_str(text)
+    }
+}
+
+impl From<realsense_sys::rs2_log_severity> for NotificationSeverity {
+    fn from(severity: realsense_sys::rs2_log_severity) -> Self {
+        use realsense_sys::rs2_log_severity::*;
+        match severity {
+            RS2_LOG_SEVERITY_DEBUG | RS2_LOG_SEVERITY_INFO => Self::Info,
+            RS2_LOG_SEVERITY_WARN => Self::Warn,
+            RS2_LOG_SEVERITY_ERROR => Self::Error,
+            RS2_LOG_SEVERITY_FATAL | RS2_LOG_SEVERITY_NONE | RS2_LOG_SEVERITY_COUNT => Self::Fatal,
+        }
+    }
+}
+
+/// Category of a [SensorNotification].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationCategory {
+    FramesTimeout,
+    FrameCorrupted,
+    HardwareError,
+    HardwareEvent,
+    UnknownError,
+    FirmwareUpdateRecommended,
+    PoseStreamDetectedLoopClosure,
+    Unknown(i32),
+}
+
+impl From<realsense_sys::rs2_notification_category> for NotificationCategory {
+    fn from(category: realsense_sys::rs2_notification_category) -> Self {
+        use realsense_sys::rs2_notification_category as Category;
+        match category {
+            Category::RS2_NOTIFICATION_CATEGORY_FRAMES_TIMEOUT => Self::FramesTimeout,
+            Category::RS2_NOTIFICATION_CATEGORY_FRAME_CORRUPTED => Self::FrameCorrupted,
+            Category::RS2_NOTIFICATION_CATEGORY_HARDWARE_ERROR => Self::HardwareError,
+            Category::RS2_NOTIFICATION_CATEGORY_HARDWARE_EVENT => Self::HardwareEvent,
+            Category::RS2_NOTIFICATION_CATEGORY_UNKNOWN_ERROR => Self::UnknownError,
+            Category::RS2_NOTIFICATION_CATEGORY_FIRMWARE_UPDATE_RECOMMENDED => {
+                Self::FirmwareUpdateRecommended
+            }
+            Category::RS2_NOTIFICATION_CATEGORY_POSE_STREAM_DETECTED_LOOP_CLOSURE => {
+                Self::PoseStreamDetectedLoopClosure
+            }
+            other => Self::Unknown(other as i32),
+        }
+    }
+}
+
+/// A single notification reported by a sensor's firmware or driver, such as
+/// a hardware error, a dropped frame, or a recommended firmware update.
+///
+/// Delivered through the channel returned by
+/// [Sensor::subscribe_notifications](crate::sensor::Sensor::subscribe_notifications).
+#[derive(Debug, Clone)]
+pub struct SensorNotification {
+    pub category: NotificationCategory,
+    pub severity: NotificationSeverity,
+    pub timestamp: f64,
+    pub description: String,
+    pub serialized_data: Option<String>,
+}
+
+impl SensorNotification {
+    /// # Safety
+    /// `ptr` must point to a valid `rs2_notification` for the duration of the call.
+    unsafe fn from_raw(ptr: *mut realsense_sys::rs2_notification) -> Option<Self> {
+        let category = {
+            let mut checker = ErrorChecker::new();
+            let val = realsense_sys::rs2_get_notification_category(ptr, checker.inner_mut_ptr());
+            checker.check().ok()?;
+            NotificationCategory::from(val)
+        };
+        let severity = {
+            let mut checker = ErrorChecker::new();
+            let val = realsense_sys::rs2_get_notification_severity(ptr, checker.inner_mut_ptr());
+            checker.check().ok()?;
+            NotificationSeverity::from(val)
+        };
+        let timestamp = {
+            let mut checker = ErrorChecker::new();
+            let val = realsense_sys::rs2_get_notification_timestamp(ptr, checker.inner_mut_ptr());
+            checker.check().ok()?;
+            val
+        };
+        let description = {
+            let mut checker = ErrorChecker::new();
+            let c_ptr =
+                realsense_sys::rs2_get_notification_description(ptr, checker.inner_mut_ptr());
+            checker.check().ok()?;
+            if c_ptr.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(c_ptr).to_string_lossy().into_owned()
+            }
+        };
+        let serialized_data = {
+            let mut checker = ErrorChecker::new();
+            let c_ptr = realsense_sys::rs2_get_notification_serialized_data(
+                ptr,
+                checker.inner_mut_ptr(),
+            );
+            match checker.check() {
+                Ok(()) if !c_ptr.is_null() => {
+                    Some(CStr::from_ptr(c_ptr).to_string_lossy().into_owned())
+                }
+                _ => None,
+            }
+        };
+
+        Some(Self {
+            category,
+            severity,
+            timestamp,
+            description,
+            serialized_data,
+        })
+    }
+}
+
+/// Trampoline registered with `rs2_set_notifications_callback`. `user_data`
+/// points at a leaked `Box<mpsc::Sender<SensorNotification>>`, reclaimed by
+/// the subscribing [Sensor](crate::sensor::Sensor) when it unsubscribes or is
+/// dropped.
+pub(crate) extern "C" fn notification_trampoline(
+    notification: *mut realsense_sys::rs2_notification,
+    user_data: *mut c_void,
+) {
+    if notification.is_null() || user_data.is_null() {
+        return;
+    }
+    let sender = unsafe { &*user_data.cast::<mpsc::Sender<SensorNotification>>() };
+    if let Some(notification) = unsafe { SensorNotification::from_raw(notification) } {
+        let _ = sender.send(notification);
+    }
+}